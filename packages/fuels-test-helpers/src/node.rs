@@ -2,8 +2,11 @@ use std::borrow::Borrow;
 use std::fmt;
 use std::io::Write;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::path::Path;
 use std::time::Duration;
 
+use figment::providers::{Env, Format, Json, Serialized, Toml};
+use figment::Figment;
 use fuel_core_interfaces::model::BlockHeight;
 use fuel_core_interfaces::model::Coin;
 use fuel_gql_client::client::FuelClient;
@@ -11,7 +14,7 @@ use fuel_gql_client::fuel_tx::{ConsensusParameters, UtxoId};
 use fuel_gql_client::fuel_vm::consts::WORD_SIZE;
 use fuel_types::{Address, AssetId, Bytes32, Word};
 use portpicker::Port;
-use serde::de::Error;
+use serde::de::Error as _;
 use serde::{Deserialize, Serialize};
 use serde::{Deserializer, Serializer};
 use serde_json::{json, Value};
@@ -19,6 +22,7 @@ use serde_with::{serde_as, skip_serializing_none};
 use serde_with::{DeserializeAs, SerializeAs};
 use std::process::Stdio;
 use tempfile::NamedTempFile;
+use thiserror::Error;
 use tokio::process::Command;
 
 #[derive(Clone, Debug)]
@@ -34,6 +38,181 @@ impl Config {
     }
 }
 
+/// Errors that can occur while resolving a [`NodeConfig`] or driving a node.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A required configuration field wasn't supplied by any layer (defaults,
+    /// config file, environment, or programmatic overrides).
+    #[error("missing configuration field `{0}`")]
+    MissingConfigField(String),
+    /// Any other failure while merging/extracting the layered configuration.
+    #[error("failed to resolve node configuration: {0}")]
+    Config(#[from] figment::Error),
+    /// An I/O failure while spawning, signalling, or waiting on a `fuel-core`
+    /// child process.
+    #[error("I/O error communicating with fuel-core: {0}")]
+    Io(#[from] std::io::Error),
+    /// `spawn()` failed because no `fuel-core` binary could be found on `PATH`.
+    #[error(
+        "Couldn't find the fuel-core binary. Please check if fuel-core is installed. \
+         Try this https://fuellabs.github.io/sway/latest/introduction/installation.html"
+    )]
+    FuelCoreNotFound,
+    /// The node never answered a health check within the allotted timeout.
+    #[error("fuel-core did not become healthy within {0:?}")]
+    NotReady(Duration),
+}
+
+impl Error {
+    fn from_figment(err: figment::Error) -> Self {
+        for nested in err.clone() {
+            if let figment::error::Kind::MissingField(field) = nested.kind {
+                return Error::MissingConfigField(field.to_string());
+            }
+        }
+        Error::Config(err)
+    }
+}
+
+/// The block production policy a spawned node should run with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum BlockProduction {
+    /// Produce a block as soon as a transaction arrives.
+    Instant,
+    /// Produce a block on a fixed interval, PoA-style, regardless of whether
+    /// any transactions are pending.
+    Interval { block_time_ms: u64 },
+}
+
+/// Describes which parent/settlement network a chain spec is anchored to.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum ParentNetworkConfig {
+    LocalTest,
+}
+
+/// Where a spawned node persists its state.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum DatabaseConfig {
+    /// Nothing survives the process exiting; the common case for one-off
+    /// test runs.
+    InMemory,
+    /// A RocksDB instance rooted at `path`. Reusing the same path across
+    /// spawns resumes the existing state instead of starting fresh.
+    ///
+    /// Hard-killing the node (e.g. letting a [`FuelService`] drop instead of
+    /// calling [`FuelService::stop`]) can leave the database mid-write and
+    /// locked, so a later spawn at the same `path` may fail to open it or
+    /// see corrupted state. Prefer `stop()`, which attempts a clean shutdown
+    /// first.
+    RocksDb { path: std::path::PathBuf },
+}
+
+/// Fully-resolved configuration for a local `fuel-core` node.
+///
+/// [`NodeConfig::load`] resolves one by layering, in increasing precedence,
+/// built-in defaults, an optional TOML/JSON config file, `FUEL_NODE_*`
+/// environment variables, and finally the caller's explicit
+/// [`NodeConfigOverrides`]. [`NodeConfig::from_preset`] and
+/// [`NodeConfig::default`] return one directly, without any layering.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct NodeConfig {
+    pub chain_name: String,
+    pub block_production: BlockProduction,
+    pub parent_network: ParentNetworkConfig,
+    pub transaction_parameters: ConsensusParameters,
+    pub database: DatabaseConfig,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            chain_name: "local_testnet".to_string(),
+            block_production: BlockProduction::Instant,
+            parent_network: ParentNetworkConfig::LocalTest,
+            transaction_parameters: ConsensusParameters::default(),
+            database: DatabaseConfig::InMemory,
+        }
+    }
+}
+
+/// Programmatic, in-code overrides for [`NodeConfig::load`].
+///
+/// Only fields set to `Some` take part in the merge; everything else is left
+/// to whatever the lower-precedence layers (defaults, file, env) resolved.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct NodeConfigOverrides {
+    pub chain_name: Option<String>,
+    pub block_production: Option<BlockProduction>,
+    pub parent_network: Option<ParentNetworkConfig>,
+    pub transaction_parameters: Option<ConsensusParameters>,
+    pub database: Option<DatabaseConfig>,
+}
+
+/// Name of the preset returned when [`NodeConfig::from_preset`] is given an
+/// unrecognized name.
+pub const DEFAULT_PRESET: &str = "local_testnet";
+
+impl NodeConfig {
+    /// Looks up a fully-populated base [`NodeConfig`] by name, mirroring how a
+    /// client library keeps a base config per network. Lets integration
+    /// tests pick a profile by string (e.g. from an env var) instead of
+    /// reconstructing the chain spec by hand.
+    ///
+    /// Unknown names fall back to the [`DEFAULT_PRESET`] rather than
+    /// panicking.
+    pub fn from_preset(name: &str) -> Self {
+        Self::lookup_preset(name).unwrap_or_else(|| {
+            Self::lookup_preset(DEFAULT_PRESET)
+                .expect("DEFAULT_PRESET must name a registered preset")
+        })
+    }
+
+    /// The preset registry backing [`NodeConfig::from_preset`]. Returns
+    /// `None` for names with no registered preset.
+    fn lookup_preset(name: &str) -> Option<Self> {
+        match name {
+            "local_testnet" => Some(Self::local_testnet_preset()),
+            _ => None,
+        }
+    }
+
+    /// The `local_testnet` preset: instant block production against a
+    /// local-only parent network, suitable for single-process test runs.
+    fn local_testnet_preset() -> Self {
+        Self::default()
+    }
+
+    /// Resolves a [`NodeConfig`] by merging, in precedence order:
+    /// 1. built-in defaults (see [`NodeConfig::default`]),
+    /// 2. an optional TOML/JSON config file (`.toml` is read as TOML,
+    ///    anything else as JSON),
+    /// 3. `FUEL_NODE_*` environment variables,
+    /// 4. the caller's explicit `overrides`.
+    ///
+    /// The last layer to set a field wins. Fails with
+    /// [`Error::MissingConfigField`] naming the field if a required key ends
+    /// up unset after all layers are merged.
+    pub fn load(config_file: Option<&Path>, overrides: NodeConfigOverrides) -> Result<Self, Error> {
+        let mut figment = Figment::from(Serialized::defaults(NodeConfig::default()));
+
+        if let Some(path) = config_file {
+            figment = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("toml") => figment.merge(Toml::file(path)),
+                _ => figment.merge(Json::file(path)),
+            };
+        }
+
+        figment = figment
+            .merge(Env::prefixed("FUEL_NODE_"))
+            .merge(Serialized::defaults(overrides));
+
+        figment.extract().map_err(Error::from_figment)
+    }
+}
+
 #[skip_serializing_none]
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
@@ -174,10 +353,9 @@ impl<'de> DeserializeAs<'de, BlockHeight> for HexNumber {
     }
 }
 
-pub fn get_node_config_json(
-    coins: Vec<(UtxoId, Coin)>,
-    consensus_parameters_config: Option<ConsensusParameters>,
-) -> Value {
+/// Serializes a resolved [`NodeConfig`] plus a coin set into the JSON chain
+/// spec `fuel-core` expects on `--chain`.
+pub fn get_node_config_json(coins: Vec<(UtxoId, Coin)>, node_config: &NodeConfig) -> Value {
     let coin_configs: Vec<Value> = coins
         .into_iter()
         .map(|(utxo_id, coin)| {
@@ -199,23 +377,15 @@ pub fn get_node_config_json(
     let coins: Value =
         serde_json::from_str(result.as_str()).expect("Failed to build config_with_coins JSON");
 
-    let consensus_parameters =
-        serde_json::to_value(consensus_parameters_config.unwrap_or_default())
-            .expect("Failed to build transaction_parameters JSON");
-
-    let config = json!({
-      "chain_name": "local_testnet",
-      "block_production": "Instant",
-      "parent_network": {
-        "type": "LocalTest"
-      },
+    json!({
+      "chain_name": node_config.chain_name,
+      "block_production": node_config.block_production,
+      "parent_network": node_config.parent_network,
       "initial_state": {
         "coins": coins
       },
-      "transaction_parameters": consensus_parameters
-    });
-
-    config
+      "transaction_parameters": node_config.transaction_parameters
+    })
 }
 
 fn write_temp_config_file(config: Value) -> NamedTempFile {
@@ -230,45 +400,402 @@ fn write_temp_config_file(config: Value) -> NamedTempFile {
     config_file.unwrap()
 }
 
-pub fn spawn_fuel_service(
+/// A handle to a single spawned `fuel-core` process.
+///
+/// Owns the child process and the temporary chain-spec file backing it (so
+/// the file isn't dropped out from under the still-running node), and
+/// provides deterministic teardown via [`FuelService::stop`] or [`Drop`]
+/// instead of relying on a detached, unobservable `tokio::spawn`.
+pub struct FuelService {
+    child: tokio::process::Child,
+    bound_address: SocketAddr,
+    _config_file: NamedTempFile,
+}
+
+impl FuelService {
+    /// The socket address the node is (or will be) listening on.
+    pub fn bound_address(&self) -> SocketAddr {
+        self.bound_address
+    }
+
+    /// Performs the readiness probe against this node, so spawn + probe is
+    /// one awaitable step.
+    pub async fn ready(&self) -> Result<(), Error> {
+        let client = FuelClient::from(self.bound_address);
+        wait_for_health(&client, DEFAULT_READINESS_TIMEOUT).await
+    }
+
+    /// Gracefully stops the node: sends SIGTERM and waits up to
+    /// [`GRACEFUL_SHUTDOWN_TIMEOUT`] for it to exit on its own before
+    /// escalating to a hard kill. On non-Unix targets no signal is sent, so
+    /// this waits out the timeout and then kills. This matters for a
+    /// [`DatabaseConfig::RocksDb`]-backed node, which needs the chance to
+    /// flush and release its lock file for the database to reopen cleanly
+    /// on the next spawn.
+    pub async fn stop(&mut self) -> Result<(), Error> {
+        send_sigterm(&self.child);
+
+        if tokio::time::timeout(GRACEFUL_SHUTDOWN_TIMEOUT, self.child.wait())
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+
+        self.child.kill().await?;
+        Ok(())
+    }
+}
+
+impl Drop for FuelService {
+    fn drop(&mut self) {
+        // Best-effort only: `Drop` can't await a graceful SIGTERM + wait like
+        // `stop()` does, so it hard-kills. Prefer an explicit
+        // `.stop().await`, especially with a `DatabaseConfig::RocksDb` path,
+        // so the database gets a chance to shut down cleanly.
+        let _ = self.child.start_kill();
+    }
+}
+
+/// Bounded wait [`FuelService::stop`] gives a SIGTERM'd node before
+/// escalating to a hard kill.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg(unix)]
+fn send_sigterm(child: &tokio::process::Child) {
+    if let Some(pid) = child.id() {
+        // SAFETY: `pid` is a valid, currently-running child PID obtained
+        // from `Child::id`; `kill` with SIGTERM is a well-defined,
+        // non-destructive signal delivery.
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn send_sigterm(_child: &tokio::process::Child) {}
+
+/// Formats a set of bootstrap peer addresses as the comma-separated
+/// multiaddr list `fuel-core --bootstrap-nodes` expects, or `None` if there
+/// are no peers to dial. Kept as a pure function so the formatting is
+/// unit-testable without spawning a process.
+fn bootstrap_nodes_arg(bootstrap_nodes: &[SocketAddr]) -> Option<String> {
+    if bootstrap_nodes.is_empty() {
+        return None;
+    }
+
+    Some(
+        bootstrap_nodes
+            .iter()
+            .map(|addr| format!("/ip4/{}/tcp/{}", addr.ip(), addr.port()))
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// Translates a [`DatabaseConfig`] into the `fuel-core` CLI flags that select
+/// it. Kept as a pure function so the translation is unit-testable without
+/// spawning a process.
+fn database_args(database: &DatabaseConfig) -> Vec<std::ffi::OsString> {
+    match database {
+        DatabaseConfig::InMemory => vec!["--db-type".into(), "in-memory".into()],
+        DatabaseConfig::RocksDb { path } => vec![
+            "--db-type".into(),
+            "rocks-db".into(),
+            "--db-path".into(),
+            path.into(),
+        ],
+    }
+}
+
+/// Spawns a `fuel-core` node per the given [`NodeConfig`] and returns a
+/// [`FuelService`] handle for observing its address and driving its
+/// shutdown.
+pub async fn spawn_fuel_service(
     coins: Vec<(UtxoId, Coin)>,
-    consensus_parameters_config: Option<ConsensusParameters>,
+    node_config: NodeConfig,
     free_port: Port,
-) {
-    tokio::spawn(async move {
-        let config = get_node_config_json(coins, consensus_parameters_config);
-        let temp_config_file = write_temp_config_file(config);
-        let mut running_node = Command::new("fuel-core")
-            .arg("--ip")
-            .arg("127.0.0.1")
-            .arg("--port")
-            .arg(free_port.to_string())
-            .arg("--chain")
-            .arg(temp_config_file.borrow().path())
-            .arg("--db-type")
-            .arg("in-memory")
-            .kill_on_drop(true)
-            .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .spawn()
-            .expect("error: Couldn't read fuel-core: No such file or directory. Please check if fuel-core library is installed. \
-        Try this https://fuellabs.github.io/sway/latest/introduction/installation.html");
-
-        running_node.wait().await
-    });
+    bootstrap_nodes: &[SocketAddr],
+) -> Result<FuelService, Error> {
+    let config = get_node_config_json(coins, &node_config);
+    let temp_config_file = write_temp_config_file(config);
+    let bound_address = SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), free_port);
+
+    let mut command = Command::new("fuel-core");
+    command
+        .arg("--ip")
+        .arg("127.0.0.1")
+        .arg("--port")
+        .arg(free_port.to_string())
+        .arg("--chain")
+        .arg(temp_config_file.borrow().path());
+
+    if let Some(peers) = bootstrap_nodes_arg(bootstrap_nodes) {
+        command.arg("--bootstrap-nodes").arg(peers);
+    }
+
+    command.args(database_args(&node_config.database));
+
+    let child = command
+        .kill_on_drop(true)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .spawn()
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => Error::FuelCoreNotFound,
+            _ => Error::Io(err),
+        })?;
+
+    Ok(FuelService {
+        child,
+        bound_address,
+        _config_file: temp_config_file,
+    })
+}
+
+/// Spawns `n` interconnected `fuel-core` nodes sharing the given chain
+/// config, wiring nodes `2..n` to dial node `1` as a bootstrap peer.
+///
+/// Returns each node's [`FuelService`] handle paired with a [`FuelClient`]
+/// already pointed at it, so tests can drive block propagation and
+/// transaction gossip across the small cluster.
+pub async fn spawn_network(
+    n: usize,
+    coins: Vec<(UtxoId, Coin)>,
+    node_config: NodeConfig,
+) -> Result<Vec<(FuelService, FuelClient)>, Error> {
+    let mut services = Vec::with_capacity(n);
+    let mut bootstrap_nodes = Vec::new();
+
+    for _ in 0..n {
+        let free_port = portpicker::pick_unused_port().expect("No free ports");
+        let service = spawn_fuel_service(
+            coins.clone(),
+            node_config.clone(),
+            free_port,
+            &bootstrap_nodes,
+        )
+        .await?;
+        service.ready().await?;
+
+        if bootstrap_nodes.is_empty() {
+            bootstrap_nodes.push(service.bound_address());
+        }
+
+        let client = FuelClient::from(service.bound_address());
+        services.push((service, client));
+    }
+
+    Ok(services)
 }
 
+/// Default total timeout used by [`FuelService::ready`] and
+/// [`server_health_check`].
+const DEFAULT_READINESS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Starting delay between health probes, doubled after each failed attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Upper bound the backoff delay is capped at.
+const MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Polls `client.health()` with exponential backoff (starting at
+/// [`INITIAL_BACKOFF`], doubling up to [`MAX_BACKOFF`]) until it reports
+/// healthy, or fails with [`Error::NotReady`] once `timeout` elapses.
+///
+/// `timeout` bounds the whole probe, including a single stuck/slow
+/// `client.health()` call (e.g. a port not yet accepting connections) — not
+/// just the sleeps between retries.
+pub async fn wait_for_health(client: &FuelClient, timeout: Duration) -> Result<(), Error> {
+    tokio::time::timeout(timeout, poll_until_healthy(client))
+        .await
+        .map_err(|_| Error::NotReady(timeout))
+}
+
+async fn poll_until_healthy(client: &FuelClient) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        if client.health().await.unwrap_or(false) {
+            return;
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Convenience wrapper over [`wait_for_health`] for the common "wait until
+/// healthy or bail" case.
 pub async fn server_health_check(client: &FuelClient) {
-    let mut attempts = 5;
-    let mut healthy = client.health().await.unwrap_or(false);
+    wait_for_health(client, DEFAULT_READINESS_TIMEOUT)
+        .await
+        .unwrap_or_else(|err| panic!("error: Could not connect to fuel core server: {err}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::Jail;
+
+    #[test]
+    fn load_with_no_file_or_overrides_resolves_to_defaults() {
+        Jail::expect_with(|_jail| {
+            let config = NodeConfig::load(None, NodeConfigOverrides::default()).unwrap();
+            assert_eq!(config, NodeConfig::default());
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn file_layer_overrides_defaults() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "node.json",
+                r#"{"chain_name": "from_file"}"#,
+            )?;
+
+            let config =
+                NodeConfig::load(Some(Path::new("node.json")), NodeConfigOverrides::default())
+                    .unwrap();
+
+            assert_eq!(config.chain_name, "from_file");
+            assert_eq!(config.block_production, BlockProduction::Instant);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn env_layer_overrides_file_layer() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "node.json",
+                r#"{"chain_name": "from_file"}"#,
+            )?;
+            jail.set_env("FUEL_NODE_CHAIN_NAME", "from_env");
+
+            let config =
+                NodeConfig::load(Some(Path::new("node.json")), NodeConfigOverrides::default())
+                    .unwrap();
+
+            assert_eq!(config.chain_name, "from_env");
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn programmatic_overrides_win_over_env_layer() {
+        Jail::expect_with(|jail| {
+            jail.set_env("FUEL_NODE_CHAIN_NAME", "from_env");
+
+            let overrides = NodeConfigOverrides {
+                chain_name: Some("from_override".to_string()),
+                ..NodeConfigOverrides::default()
+            };
+            let config = NodeConfig::load(None, overrides).unwrap();
+
+            assert_eq!(config.chain_name, "from_override");
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn missing_nested_field_surfaces_its_name() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "node.json",
+                r#"{"block_production": {"Interval": {}}}"#,
+            )?;
+
+            let err =
+                NodeConfig::load(Some(Path::new("node.json")), NodeConfigOverrides::default())
+                    .unwrap_err();
+
+            assert!(matches!(
+                err,
+                Error::MissingConfigField(field) if field == "block_time_ms"
+            ));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn lookup_preset_only_recognizes_registered_names() {
+        assert!(NodeConfig::lookup_preset("local_testnet").is_some());
+        assert!(NodeConfig::lookup_preset("does-not-exist").is_none());
+    }
+
+    #[test]
+    fn from_preset_falls_back_to_default_preset_for_unknown_names() {
+        assert_eq!(
+            NodeConfig::from_preset("does-not-exist"),
+            NodeConfig::from_preset(DEFAULT_PRESET)
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_health_times_out_promptly_against_an_unreachable_node() {
+        let port = portpicker::pick_unused_port().expect("No free ports");
+        let client = FuelClient::from(SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), port));
+
+        let started = std::time::Instant::now();
+        let result = wait_for_health(&client, Duration::from_millis(200)).await;
+
+        assert!(matches!(result, Err(Error::NotReady(_))));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn bootstrap_nodes_arg_is_none_with_no_peers() {
+        assert_eq!(bootstrap_nodes_arg(&[]), None);
+    }
+
+    #[test]
+    fn bootstrap_nodes_arg_formats_one_peer_as_a_multiaddr() {
+        let peer: SocketAddr = "127.0.0.1:4000".parse().unwrap();
+        assert_eq!(
+            bootstrap_nodes_arg(&[peer]),
+            Some("/ip4/127.0.0.1/tcp/4000".to_string())
+        );
+    }
+
+    #[test]
+    fn bootstrap_nodes_arg_joins_multiple_peers_with_commas() {
+        let peers: Vec<SocketAddr> = vec![
+            "127.0.0.1:4000".parse().unwrap(),
+            "127.0.0.1:4001".parse().unwrap(),
+        ];
+        assert_eq!(
+            bootstrap_nodes_arg(&peers),
+            Some("/ip4/127.0.0.1/tcp/4000,/ip4/127.0.0.1/tcp/4001".to_string())
+        );
+    }
 
-    while attempts > 0 && !healthy {
-        healthy = client.health().await.unwrap_or(false);
-        tokio::time::sleep(Duration::from_millis(100)).await;
-        attempts -= 1;
+    #[test]
+    fn database_args_selects_in_memory_flags() {
+        let args = database_args(&DatabaseConfig::InMemory);
+        assert_eq!(
+            args,
+            vec![
+                std::ffi::OsString::from("--db-type"),
+                std::ffi::OsString::from("in-memory"),
+            ]
+        );
     }
 
-    if !healthy {
-        panic!("error: Could not connect to fuel core server.")
+    #[test]
+    fn database_args_selects_rocks_db_flags_with_path() {
+        let path = std::path::PathBuf::from("/tmp/fuel-db");
+        let args = database_args(&DatabaseConfig::RocksDb { path: path.clone() });
+        assert_eq!(
+            args,
+            vec![
+                std::ffi::OsString::from("--db-type"),
+                std::ffi::OsString::from("rocks-db"),
+                std::ffi::OsString::from("--db-path"),
+                std::ffi::OsString::from(path),
+            ]
+        );
     }
 }